@@ -13,6 +13,41 @@ pub struct Blocks {
 
 impl Blocks {
     pub fn block_of(&self, c: char) -> &str {
+        self.find(c)
+            .map(|i| &*self.ranges[i].1)
+            .unwrap_or("No_Block")
+    }
+
+    /// The range of code points in the block containing `c`, or `None` if `c` falls in no
+    /// known block.
+    pub fn block_range_of(&self, c: char) -> Option<RangeInclusive<u32>> {
+        self.find(c).map(|i| self.ranges[i].0.clone())
+    }
+
+    /// The range of code points spanned by the block named `name`, or `None` if there is no
+    /// block with that name.
+    pub fn range_of_block(&self, name: &str) -> Option<RangeInclusive<u32>> {
+        self.ranges
+            .iter()
+            .find(|(_, block_name)| block_name == name)
+            .map(|(range, _)| range.clone())
+    }
+
+    /// Every block in the table, in ascending order, as its range and name.
+    pub fn blocks(&self) -> impl Iterator<Item = (RangeInclusive<u32>, &str)> {
+        self.ranges
+            .iter()
+            .map(|(range, name)| (range.clone(), name.as_str()))
+    }
+
+    /// Whether `c` falls in the block named `block_name`.
+    pub fn contains(&self, c: char, block_name: &str) -> bool {
+        self.block_of(c) == block_name
+    }
+
+    /// The index into `ranges` of the block containing `c`, found via binary search since
+    /// `ranges` is sorted.
+    fn find(&self, c: char) -> Option<usize> {
         self.ranges
             .binary_search_by(|(range, _)| {
                 if *range.end() < u32::from(c) {
@@ -23,8 +58,7 @@ impl Blocks {
                     cmp::Ordering::Equal
                 }
             })
-            .map(|i| &*self.ranges[i].1)
-            .unwrap_or("No_Block")
+            .ok()
     }
 
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, FromFileError> {
@@ -43,38 +77,167 @@ impl Blocks {
         Self::from_str(&response.into_string().map_err(DownloadError::read_body())?)
             .map_err(DownloadError::parse())
     }
+
+    /// Like [`from_file`](Self::from_file), but keeps parsing past malformed lines instead of
+    /// bailing out on the first one, returning every [`ParseError`] it finds.
+    ///
+    /// This is useful when validating a whole UCD file: you get one report covering every
+    /// problem line rather than having to fix and re-run one line at a time.
+    pub fn from_file_collecting<P: AsRef<Path>>(path: P) -> Result<Self, FromFileError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(FromFileError::read_file(path))?;
+
+        Self::parse_collecting(&data).map_err(FromFileError::parse_all(path))
+    }
+
+    /// Like [`FromStr::from_str`], but keeps parsing past malformed lines instead of bailing
+    /// out on the first one, gathering every [`ParseError`] into a [`ParseErrors`].
+    pub fn parse_collecting(s: &str) -> Result<Self, ParseErrors> {
+        let mut ranges = Vec::new();
+        let mut errors = Vec::new();
+
+        for record in UcdRecords::new(s) {
+            match record.and_then(Self::range_and_name) {
+                Ok(entry) => ranges.push(entry),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Self { ranges })
+        } else {
+            Err(ParseErrors { errors })
+        }
+    }
+
+    /// Blocks.txt records are `range ; name`, so the block name is always the second field.
+    ///
+    /// Unlike [`UcdRecords`] in general, Blocks.txt records are always `start..end` ranges, never
+    /// a bare code point, so a bare code point is rejected here with [`ParseErrorKind::NoDotDot`].
+    fn range_and_name(record: UcdRecord<'_>) -> Result<(RangeInclusive<u32>, String), ParseError> {
+        if !record.is_range {
+            let span = span_of(record.text, record.fields[0]);
+            return Err(ParseError::new(
+                record.line,
+                record.text,
+                span,
+                ParseErrorKind::NoDotDot,
+            ));
+        }
+
+        let name = record.fields.get(1).copied().unwrap_or_default();
+        Ok((record.range, name.to_owned()))
+    }
 }
 
 impl FromStr for Blocks {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let ranges = s
-            .lines()
-            .enumerate()
-            .map(|(i, line)| {
-                (
-                    i,
-                    line.split_once('#').map(|(line, _)| line).unwrap_or(line),
-                )
-            })
-            .filter(|(_, line)| !line.is_empty())
-            .map(|(i, line)| {
-                let (range, name) = line
-                    .split_once(';')
-                    .ok_or(ParseError::new(i, ParseErrorKind::NoSemicolon))?;
-                let (range, name) = (range.trim(), name.trim());
+        let ranges = UcdRecords::new(s)
+            .map(|record| record.and_then(Self::range_and_name))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        Ok(Self { ranges })
+    }
+}
+
+/// A generic parser for the semicolon-delimited record grammar shared by most UCD data files
+/// (`Blocks.txt`, `Scripts.txt`, `PropList.txt`, `DerivedAge.txt`, and others): `#`-comments,
+/// blank lines, and `;`-separated fields whose first field is either a single code point
+/// (`0041`) or a `start..end` range.
+///
+/// [`Blocks::from_str`] is built on top of this; other UCD files can reuse it (and the same
+/// [`ParseError`] reporting) instead of rewriting the splitter.
+pub struct UcdRecords<'a> {
+    lines: Lines<'a>,
+    line_no: usize,
+}
+
+/// One semicolon-delimited record yielded by [`UcdRecords`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct UcdRecord<'a> {
+    pub line: usize,
+    /// The raw (comment-stripped, untrimmed) text of the line this record came from, for callers
+    /// that want to re-raise a [`ParseError`] of their own (e.g. rejecting a bare code point
+    /// where their format requires a range).
+    pub text: &'a str,
+    pub range: RangeInclusive<u32>,
+    /// Whether `fields[0]` was a `start..end` range as opposed to a bare code point (in which
+    /// case `range` is `c..=c`).
+    pub is_range: bool,
+    pub fields: Vec<&'a str>,
+}
 
-                let (start, end) = range
-                    .split_once("..")
-                    .ok_or(ParseError::new(i, ParseErrorKind::NoDotDot))?;
+impl<'a> UcdRecords<'a> {
+    pub fn new(s: &'a str) -> Self {
+        UcdRecords {
+            lines: s.lines(),
+            line_no: 0,
+        }
+    }
 
-                let start = u32::from_str_radix(start, 16).map_err(ParseError::parse_int(i))?;
-                let end = u32::from_str_radix(end, 16).map_err(ParseError::parse_int(i))?;
+    fn parse_record(i: usize, line: &'a str) -> Result<UcdRecord<'a>, ParseError> {
+        let (first, _) = line.split_once(';').ok_or_else(|| {
+            ParseError::new(i, line, 0..line.len(), ParseErrorKind::NoSemicolon)
+        })?;
+        let first = first.trim();
+
+        let (range, is_range) = Self::parse_range(i, line, first)?;
+        let fields = line.split(';').map(str::trim).collect();
+
+        Ok(UcdRecord {
+            line: i,
+            text: line,
+            range,
+            is_range,
+            fields,
+        })
+    }
 
-                Ok((start..=end, name.to_owned()))
-            })
-            .collect::<Result<Vec<_>, ParseError>>()?;
-        Ok(Self { ranges })
+    fn parse_range(
+        i: usize,
+        line: &'a str,
+        first: &'a str,
+    ) -> Result<(RangeInclusive<u32>, bool), ParseError> {
+        if let Some((start, end)) = first.split_once("..") {
+            let (start, end) = (start.trim(), end.trim());
+            let start_span = span_of(line, start);
+            let end_span = span_of(line, end);
+            let start = u32::from_str_radix(start, 16)
+                .map_err(ParseError::parse_int(i, line, start_span))?;
+            let end = u32::from_str_radix(end, 16)
+                .map_err(ParseError::parse_int(i, line, end_span))?;
+            Ok((start..=end, true))
+        } else {
+            let span = span_of(line, first);
+            let c = u32::from_str_radix(first, 16).map_err(ParseError::parse_int(i, line, span))?;
+            Ok((c..=c, false))
+        }
+    }
+}
+
+/// The byte range `piece` occupies within `line`, assuming `piece` is a substring of `line`.
+fn span_of(line: &str, piece: &str) -> Range<usize> {
+    let start = piece.as_ptr() as usize - line.as_ptr() as usize;
+    start..start + piece.len()
+}
+
+impl<'a> Iterator for UcdRecords<'a> {
+    type Item = Result<UcdRecord<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let raw = self.lines.next()?;
+            let i = self.line_no;
+            self.line_no += 1;
+
+            let line = raw.split_once('#').map(|(line, _)| line).unwrap_or(raw);
+            if line.is_empty() {
+                continue;
+            }
+
+            return Some(Self::parse_record(i, line));
+        }
     }
 }
 
@@ -82,6 +245,9 @@ impl FromStr for Blocks {
 #[non_exhaustive]
 pub struct DownloadError {
     pub kind: DownloadErrorKind,
+    /// A human-readable description of what was being attempted, e.g. `"loading the bundled
+    /// block table"`, shown ahead of the error message when present.
+    pub context: Option<&'static str>,
 }
 
 #[derive(Debug)]
@@ -95,22 +261,54 @@ impl DownloadError {
     pub fn request() -> impl FnOnce(ureq::Error) -> Self {
         move |error: ureq::Error| DownloadError {
             kind: DownloadErrorKind::Request(Box::new(error)),
+            context: None,
+        }
+    }
+    pub fn request_ctx(context: &'static str) -> impl FnOnce(ureq::Error) -> Self {
+        move |error: ureq::Error| DownloadError {
+            kind: DownloadErrorKind::Request(Box::new(error)),
+            context: Some(context),
         }
     }
     pub fn read_body() -> impl FnOnce(io::Error) -> Self {
         move |error: io::Error| DownloadError {
             kind: DownloadErrorKind::ReadBody(error),
+            context: None,
+        }
+    }
+    pub fn read_body_ctx(context: &'static str) -> impl FnOnce(io::Error) -> Self {
+        move |error: io::Error| DownloadError {
+            kind: DownloadErrorKind::ReadBody(error),
+            context: Some(context),
         }
     }
     pub fn parse() -> impl FnOnce(ParseError) -> Self {
         move |error: ParseError| DownloadError {
             kind: DownloadErrorKind::Parse(error),
+            context: None,
+        }
+    }
+    pub fn parse_ctx(context: &'static str) -> impl FnOnce(ParseError) -> Self {
+        move |error: ParseError| DownloadError {
+            kind: DownloadErrorKind::Parse(error),
+            context: Some(context),
         }
     }
+
+    /// Attach a human-readable description of what was being attempted, shown ahead of the error
+    /// message, e.g. `.map_err(DownloadError::request()).map_err(|e| e.context("refreshing the
+    /// cached block table"))`.
+    pub fn context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+        self
+    }
 }
 
 impl Display for DownloadError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(context) = self.context {
+            write!(f, "{context}: ")?;
+        }
         write!(f, "failed to download Blocks.txt from the Unicode website")
     }
 }
@@ -130,12 +328,16 @@ impl Error for DownloadError {
 pub struct FromFileError {
     pub path: Box<Path>,
     pub kind: FromFileErrorKind,
+    /// A human-readable description of what was being attempted, shown ahead of the error
+    /// message when present.
+    pub context: Option<&'static str>,
 }
 
 #[derive(Debug)]
 pub enum FromFileErrorKind {
     ReadFile(io::Error),
     Parse(ParseError),
+    ParseAll(ParseErrors),
 }
 
 impl FromFileError {
@@ -146,6 +348,21 @@ impl FromFileError {
         move |error: io::Error| FromFileError {
             path: path.into(),
             kind: FromFileErrorKind::ReadFile(error),
+            context: None,
+        }
+    }
+
+    pub fn read_file_ctx<P>(
+        path: P,
+        context: &'static str,
+    ) -> impl FnOnce(io::Error) -> FromFileError
+    where
+        P: Into<Box<Path>>,
+    {
+        move |error: io::Error| FromFileError {
+            path: path.into(),
+            kind: FromFileErrorKind::ReadFile(error),
+            context: Some(context),
         }
     }
 
@@ -156,12 +373,63 @@ impl FromFileError {
         move |error: ParseError| FromFileError {
             path: path.into(),
             kind: FromFileErrorKind::Parse(error),
+            context: None,
+        }
+    }
+
+    pub fn parse_ctx<P>(
+        path: P,
+        context: &'static str,
+    ) -> impl FnOnce(ParseError) -> FromFileError
+    where
+        P: Into<Box<Path>>,
+    {
+        move |error: ParseError| FromFileError {
+            path: path.into(),
+            kind: FromFileErrorKind::Parse(error),
+            context: Some(context),
+        }
+    }
+
+    pub fn parse_all<P>(path: P) -> impl FnOnce(ParseErrors) -> FromFileError
+    where
+        P: Into<Box<Path>>,
+    {
+        move |error: ParseErrors| FromFileError {
+            path: path.into(),
+            kind: FromFileErrorKind::ParseAll(error),
+            context: None,
         }
     }
+
+    pub fn parse_all_ctx<P>(
+        path: P,
+        context: &'static str,
+    ) -> impl FnOnce(ParseErrors) -> FromFileError
+    where
+        P: Into<Box<Path>>,
+    {
+        move |error: ParseErrors| FromFileError {
+            path: path.into(),
+            kind: FromFileErrorKind::ParseAll(error),
+            context: Some(context),
+        }
+    }
+
+    /// Attach a human-readable description of what was being attempted, shown ahead of the error
+    /// message, so callers can disambiguate multiple `from_file` calls in one operation without
+    /// wrapping in yet another error enum.
+    pub fn context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+        self
+    }
 }
 
 impl Display for FromFileError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(context) = self.context {
+            write!(f, "{context}: ")?;
+        }
         write!(f, "error reading `{}`", self.path.display())
     }
 }
@@ -171,6 +439,7 @@ impl Error for FromFileError {
         match &self.kind {
             FromFileErrorKind::ReadFile(e) => Some(e),
             FromFileErrorKind::Parse(e) => Some(e),
+            FromFileErrorKind::ParseAll(e) => Some(e),
         }
     }
 }
@@ -179,7 +448,14 @@ impl Error for FromFileError {
 #[non_exhaustive]
 pub struct ParseError {
     pub line: usize,
+    /// The raw (comment-stripped, untrimmed) text of the offending line.
+    pub text: String,
+    /// The byte range within `text` that `kind` points at.
+    pub span: Range<usize>,
     pub kind: ParseErrorKind,
+    /// A human-readable description of what was being attempted, shown ahead of the error
+    /// message when present.
+    pub context: Option<&'static str>,
 }
 
 #[derive(Debug)]
@@ -193,20 +469,94 @@ pub enum ParseErrorKind {
 }
 
 impl ParseError {
-    pub fn new(line: usize, kind: ParseErrorKind) -> Self {
-        ParseError { line, kind }
+    pub fn new(line: usize, text: &str, span: Range<usize>, kind: ParseErrorKind) -> Self {
+        ParseError {
+            line,
+            text: text.to_owned(),
+            span,
+            kind,
+            context: None,
+        }
+    }
+
+    pub fn new_ctx(
+        line: usize,
+        text: &str,
+        span: Range<usize>,
+        kind: ParseErrorKind,
+        context: &'static str,
+    ) -> Self {
+        ParseError {
+            line,
+            text: text.to_owned(),
+            span,
+            kind,
+            context: Some(context),
+        }
     }
 
-    pub fn parse_int(line: usize) -> impl FnOnce(ParseIntError) -> Self {
+    pub fn parse_int(
+        line: usize,
+        text: &str,
+        span: Range<usize>,
+    ) -> impl FnOnce(ParseIntError) -> Self {
+        let text = text.to_owned();
         move |error: ParseIntError| ParseError {
             line,
+            text,
+            span,
             kind: ParseErrorKind::ParseInt(error),
+            context: None,
         }
     }
+
+    pub fn parse_int_ctx(
+        line: usize,
+        text: &str,
+        span: Range<usize>,
+        context: &'static str,
+    ) -> impl FnOnce(ParseIntError) -> Self {
+        let text = text.to_owned();
+        move |error: ParseIntError| ParseError {
+            line,
+            text,
+            span,
+            kind: ParseErrorKind::ParseInt(error),
+            context: Some(context),
+        }
+    }
+
+    /// Attach a human-readable description of what was being attempted, shown ahead of the error
+    /// message.
+    pub fn context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Render a multi-line diagnostic pointing at the offending span within [`text`](Self::text),
+    /// similar to the verbose error output of parser combinator crates like `nom` or `winnow`.
+    ///
+    /// ```text
+    /// line 3: 0041..XYZW ; Basic Latin
+    ///               ^^^^ one end of range is not a valid hexadecimal integer
+    /// ```
+    pub fn render(&self) -> String {
+        let len = self.text.len();
+        let start = self.span.start.min(len);
+        let end = self.span.end.clamp(start, len);
+
+        let gutter = format!("line {}: ", self.line + 1);
+        let underline = " ".repeat(gutter.len() + start) + &"^".repeat((end - start).max(1));
+
+        format!("{gutter}{}\n{underline} {}", self.text, self.kind)
+    }
 }
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(context) = self.context {
+            write!(f, "{context}: ")?;
+        }
         write!(f, "invalid Blocks.txt data on line {}", self.line + 1)
     }
 }
@@ -217,6 +567,38 @@ impl Error for ParseError {
     }
 }
 
+/// Every [`ParseError`] found while parsing with
+/// [`Blocks::parse_collecting`](crate::Blocks::parse_collecting) or
+/// [`Blocks::from_file_collecting`](crate::Blocks::from_file_collecting), rather than just the
+/// first one.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ParseErrors {
+    pub errors: Vec<ParseError>,
+}
+
+impl Display for ParseErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.errors.first(), self.errors.last()) {
+            (Some(first), Some(last)) if self.errors.len() > 1 => write!(
+                f,
+                "{} invalid lines in Blocks.txt data, from line {} to line {}",
+                self.errors.len(),
+                first.line + 1,
+                last.line + 1,
+            ),
+            (Some(first), _) => write!(f, "invalid Blocks.txt data on line {}", first.line + 1),
+            (None, _) => write!(f, "no invalid Blocks.txt data"),
+        }
+    }
+}
+
+impl Error for ParseErrors {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors.first().map(|error| error as &(dyn Error + 'static))
+    }
+}
+
 impl Display for ParseErrorKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match *self {
@@ -249,7 +631,99 @@ mod tests {
         assert_eq!(data.block_of('\u{EFFFF}'), "No_Block");
     }
 
+    #[test]
+    fn rejects_bare_code_point() {
+        let Err(err) = "0041 ; Single\n".parse::<Blocks>() else {
+            panic!("expected a NoDotDot parse error");
+        };
+        assert!(matches!(err.kind, ParseErrorKind::NoDotDot));
+    }
+
+    #[test]
+    fn ucd_records_accepts_bare_code_point_and_extra_fields() {
+        let records = UcdRecords::new("0041 ; Latin ; Alphabetic\n")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(!records[0].is_range);
+        assert_eq!(records[0].range, 0x41..=0x41);
+        assert_eq!(records[0].fields, vec!["0041", "Latin", "Alphabetic"]);
+    }
+
+    #[test]
+    fn parse_collecting_reports_every_malformed_line() {
+        let mixed = "0000..007F ; Basic Latin\nbadline\n0080..00FF ; Latin-1 Supplement\nalsobad\n";
+        let Err(errors) = Blocks::parse_collecting(mixed) else {
+            panic!("expected the malformed lines to produce ParseErrors");
+        };
+        assert_eq!(errors.errors.len(), 2);
+        assert_eq!(errors.errors[0].line, 1);
+        assert_eq!(errors.errors[1].line, 3);
+        assert_eq!(
+            errors.to_string(),
+            "2 invalid lines in Blocks.txt data, from line 2 to line 4"
+        );
+
+        use std::error::Error as _;
+        assert!(errors.source().is_some());
+
+        // On error the valid ranges are not returned alongside the errors, so check separately
+        // that the same input with just the malformed lines removed collects into a working
+        // Blocks table.
+        let valid_only = "0000..007F ; Basic Latin\n0080..00FF ; Latin-1 Supplement\n";
+        let data = Blocks::parse_collecting(valid_only).unwrap();
+        assert_eq!(data.block_of('A'), "Basic Latin");
+        assert_eq!(data.block_of('\u{00FF}'), "Latin-1 Supplement");
+    }
+
+    #[test]
+    fn render_underlines_the_offending_span() {
+        let Err(err) = "0041..XYZW ; Latin\n".parse::<Blocks>() else {
+            panic!("expected a ParseInt parse error");
+        };
+        let rendered = err.render();
+        assert!(rendered.contains("XYZW"));
+        assert!(rendered.contains("^^^^"));
+    }
+
+    #[test]
+    fn context_is_prepended_to_display() {
+        let Err(err) = "0041 ; Single\n".parse::<Blocks>() else {
+            panic!("expected a NoDotDot parse error");
+        };
+        let err = err.context("validating test data");
+        let rendered = err.to_string();
+        assert!(rendered.starts_with("validating test data: "));
+        assert!(rendered.ends_with("invalid Blocks.txt data on line 1"));
+    }
+
+    #[test]
+    fn reverse_and_range_queries() {
+        let data = "0000..007F ; Basic Latin\n0080..00FF ; Latin-1 Supplement\n"
+            .parse::<Blocks>()
+            .unwrap();
+
+        assert_eq!(data.block_range_of('A'), Some(0x0000..=0x007F));
+        assert_eq!(data.block_range_of('\u{1F600}'), None);
+        assert_eq!(
+            data.range_of_block("Latin-1 Supplement"),
+            Some(0x0080..=0x00FF)
+        );
+        assert_eq!(data.range_of_block("Nonexistent"), None);
+        assert!(data.contains('A', "Basic Latin"));
+        assert!(!data.contains('A', "Latin-1 Supplement"));
+        assert_eq!(
+            data.blocks().collect::<Vec<_>>(),
+            vec![
+                (0x0000..=0x007F, "Basic Latin"),
+                (0x0080..=0x00FF, "Latin-1 Supplement"),
+            ]
+        );
+    }
+
     use crate::Blocks;
+    use crate::ParseErrorKind;
+    use crate::UcdRecords;
 }
 
 pub const LATEST_URL: &str = "https://www.unicode.org/Public/UCD/latest/ucd/Blocks.txt";
@@ -262,6 +736,8 @@ use std::fmt::Formatter;
 use std::fs;
 use std::io;
 use std::num::ParseIntError;
+use std::ops::Range;
 use std::ops::RangeInclusive;
 use std::path::Path;
 use std::str::FromStr;
+use std::str::Lines;